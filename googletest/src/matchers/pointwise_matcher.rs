@@ -41,6 +41,25 @@
 /// `Vec` or an array. The container does not have to have the same type as the
 /// actual value, but the value type must be the same.
 ///
+/// The macro also accepts more than one container, in which case `$matcher`
+/// is treated as an n-ary function and is applied to the corresponding
+/// elements of each container zipped together:
+///
+/// ```
+/// fn sum_is(a: i32, b: i32) -> impl Matcher<i32> {
+///     eq(a + b)
+/// }
+///
+/// let value = vec![3, 5, 7];
+/// let c1 = vec![1, 2, 3];
+/// let c2 = vec![2, 3, 4];
+/// verify_that!(value, pointwise!(sum_is, c1, c2))?; // Passes
+/// ```
+///
+/// If the containers have different lengths, the resulting matcher has as
+/// many elements as the shortest one; this is normally caught by the usual
+/// size mismatch reporting against the actual container.
+///
 /// **Note for users of the [`Pointwise`] matcher in C++ GoogleTest:**
 ///
 /// This macro differs from `Pointwise` in that the first parameter is not a
@@ -63,6 +82,33 @@ macro_rules! pointwise {
         use $crate::matchers::pointwise_matcher::internal::PointwiseMatcher;
         PointwiseMatcher::new($container.into_iter().map(|t| $matcher(t)).collect())
     }};
+
+    ($matcher:expr, $c1:expr, $c2:expr) => {{
+        #[cfg(google3)]
+        use $crate::internal::PointwiseMatcher;
+        #[cfg(not(google3))]
+        use $crate::matchers::pointwise_matcher::internal::PointwiseMatcher;
+        PointwiseMatcher::new(
+            $c1.into_iter()
+                .zip($c2.into_iter())
+                .map(|(a, b)| $matcher(a, b))
+                .collect(),
+        )
+    }};
+
+    ($matcher:expr, $c1:expr, $c2:expr, $c3:expr) => {{
+        #[cfg(google3)]
+        use $crate::internal::PointwiseMatcher;
+        #[cfg(not(google3))]
+        use $crate::matchers::pointwise_matcher::internal::PointwiseMatcher;
+        PointwiseMatcher::new(
+            $c1.into_iter()
+                .zip($c2.into_iter())
+                .zip($c3.into_iter())
+                .map(|((a, b), c)| $matcher(a, b, c))
+                .collect(),
+        )
+    }};
 }
 
 /// Module for use only by the procedural macros in this module.
@@ -120,24 +166,18 @@ pub mod internal {
             // TODO(b/260819741) This code duplicates elements_are_matcher.rs. Consider
             // extract as a separate library. (or implement pointwise! with
             // elements_are)
-            let actual_iterator = actual.into_iter();
-            let mut zipped_iterator = zip(actual_iterator, self.matchers.iter());
+            let actual: Vec<&T> = actual.into_iter().collect();
+            if actual.len() != self.matchers.len() {
+                return MatchExplanation::create(explain_length_mismatch(&actual, &self.matchers));
+            }
             let mut mismatches = Vec::new();
-            for (idx, (a, e)) in zipped_iterator.by_ref().enumerate() {
+            for (idx, (a, e)) in actual.iter().copied().zip(self.matchers.iter()).enumerate() {
                 if matches!(e.matches(a), MatcherResult::DoesNotMatch) {
                     mismatches.push(format!("element #{idx} is {a:?}, {}", e.explain_match(a)));
                 }
             }
             if mismatches.is_empty() {
-                if !zipped_iterator.has_size_mismatch() {
-                    MatchExplanation::create("which matches all elements".to_string())
-                } else {
-                    MatchExplanation::create(format!(
-                        "which has size {} (expected {})",
-                        zipped_iterator.left_size(),
-                        self.matchers.len()
-                    ))
-                }
+                MatchExplanation::create("which matches all elements".to_string())
             } else if mismatches.len() == 1 {
                 MatchExplanation::create(format!("where {}", mismatches[0]))
             } else {
@@ -159,6 +199,115 @@ pub mod internal {
             )
         }
     }
+
+    /// One step of an alignment between the actual elements and the expected
+    /// matchers, analogous to the steps of a Levenshtein-style edit distance.
+    enum Edit {
+        /// Actual element `.0` corresponds to matcher `.1` and matches it.
+        Match(usize, usize),
+        /// Actual element `.0` corresponds to matcher `.1` but does not match
+        /// it.
+        Substitute(usize, usize),
+        /// Actual element `.0` has no corresponding matcher.
+        Surplus(usize),
+        /// Matcher `.0` has no corresponding actual element.
+        Missing(usize),
+    }
+
+    /// Aligns `actual` against `matchers`, treating a correspondence between
+    /// an actual element and a matcher it does not match as a substitution
+    /// and a length difference as a run of insertions or deletions, and
+    /// returns the edit script of the cheapest such alignment.
+    ///
+    /// This uses the same dynamic programming approach as the edit distance
+    /// computed for string diffs, but the cost of a diagonal step is driven
+    /// by whether the matcher in question matches the actual element rather
+    /// than by character equality.
+    fn align<T, MatcherT: Matcher<T>>(actual: &[&T], matchers: &[MatcherT]) -> Vec<Edit> {
+        let is_match =
+            |i: usize, j: usize| matches!(matchers[j].matches(actual[i]), MatcherResult::Matches);
+        let n = actual.len();
+        let m = matchers.len();
+        let mut cost = vec![vec![0usize; m + 1]; n + 1];
+        for (i, row) in cost.iter_mut().enumerate().take(n + 1).skip(1) {
+            row[0] = i;
+        }
+        for j in 1..=m {
+            cost[0][j] = j;
+        }
+        for i in 1..=n {
+            for j in 1..=m {
+                let substitute_cost = cost[i - 1][j - 1] + usize::from(!is_match(i - 1, j - 1));
+                let surplus_cost = cost[i - 1][j] + 1;
+                let missing_cost = cost[i][j - 1] + 1;
+                cost[i][j] = substitute_cost.min(surplus_cost).min(missing_cost);
+            }
+        }
+        let mut edits = Vec::new();
+        let (mut i, mut j) = (n, m);
+        while i > 0 || j > 0 {
+            if i > 0
+                && j > 0
+                && cost[i][j] == cost[i - 1][j - 1] + usize::from(!is_match(i - 1, j - 1))
+            {
+                edits.push(if is_match(i - 1, j - 1) {
+                    Edit::Match(i - 1, j - 1)
+                } else {
+                    Edit::Substitute(i - 1, j - 1)
+                });
+                i -= 1;
+                j -= 1;
+            } else if i > 0 && cost[i][j] == cost[i - 1][j] + 1 {
+                edits.push(Edit::Surplus(i - 1));
+                i -= 1;
+            } else {
+                edits.push(Edit::Missing(j - 1));
+                j -= 1;
+            }
+        }
+        edits.reverse();
+        edits
+    }
+
+    /// Explains a length mismatch between `actual` and `matchers` by aligning
+    /// them via [`align`] and describing every inserted, deleted, or
+    /// mismatched element, so that a single element inserted or deleted in
+    /// the middle does not appear as a cascade of downstream mismatches.
+    fn explain_length_mismatch<T: Debug, MatcherT: Matcher<T>>(
+        actual: &[&T],
+        matchers: &[MatcherT],
+    ) -> String {
+        let descriptions: Vec<String> = align(actual, matchers)
+            .into_iter()
+            .filter_map(|edit| match edit {
+                Edit::Match(..) => None,
+                Edit::Substitute(i, j) => Some(format!(
+                    "element #{i} is {:?}, {}",
+                    actual[i],
+                    matchers[j].explain_match(actual[i])
+                )),
+                Edit::Surplus(i) => Some(format!(
+                    "element #{i} is {:?}, which is unexpected",
+                    actual[i]
+                )),
+                Edit::Missing(j) => Some(format!(
+                    "no element matches #{j}: expected {}",
+                    matchers[j].describe(MatcherResult::Matches)
+                )),
+            })
+            .collect();
+        let header = format!(
+            "which has size {} (expected {})",
+            actual.len(),
+            matchers.len()
+        );
+        if descriptions.len() == 1 {
+            format!("{header}; where {}", descriptions[0])
+        } else {
+            let descriptions = descriptions.into_iter().collect::<Description>();
+            format!("{header}; where:\n{}", descriptions.bullet_list().indent())
+        }
+    }
 }
 
 #[cfg(test)]
@@ -167,7 +316,7 @@ mod tests {
     use crate as googletest;
     #[cfg(not(google3))]
     use googletest::matchers;
-    use googletest::{google_test, verify_that, Result};
+    use googletest::{google_test, matcher::Matcher, verify_that, Result};
     use indoc::indoc;
     use matchers::{contains_substring, displays_as, eq, err, lt, not};
 
@@ -214,6 +363,39 @@ mod tests {
         verify_that!(value, not(pointwise!(lt, vec![2, 2])))
     }
 
+    #[google_test]
+    fn pointwise_matches_elements_with_binary_matcher_over_two_containers() -> Result<()> {
+        let value = vec![3, 5, 7];
+        let c1 = vec![1, 2, 3];
+        let c2 = vec![2, 3, 4];
+        verify_that!(value, pointwise!(sum_is, c1, c2))
+    }
+
+    #[google_test]
+    fn pointwise_matches_elements_with_ternary_matcher_over_three_containers() -> Result<()> {
+        let value = vec![6, 9, 12];
+        let c1 = vec![1, 2, 3];
+        let c2 = vec![2, 3, 4];
+        let c3 = vec![3, 4, 5];
+        verify_that!(value, pointwise!(sum_of_three_is, c1, c2, c3))
+    }
+
+    #[google_test]
+    fn pointwise_does_not_match_elements_with_binary_matcher_when_one_fails() -> Result<()> {
+        let value = vec![3, 5, 8];
+        let c1 = vec![1, 2, 3];
+        let c2 = vec![2, 3, 4];
+        verify_that!(value, not(pointwise!(sum_is, c1, c2)))
+    }
+
+    fn sum_is(a: i32, b: i32) -> impl Matcher<i32> {
+        eq(a + b)
+    }
+
+    fn sum_of_three_is(a: i32, b: i32, c: i32) -> impl Matcher<i32> {
+        eq(a + b + c)
+    }
+
     #[google_test]
     fn pointwise_allows_qualified_matcher_name() -> Result<()> {
         mod submodule {
@@ -239,12 +421,36 @@ mod tests {
                     1,
                     2,
                     3,
-                ], which has size 3 (expected 2)
+                ], which has size 3 (expected 2); where element #2 is 3, which is unexpected
                 "
             ))))
         )
     }
 
+    #[google_test]
+    fn pointwise_aligns_actual_value_with_an_element_inserted_in_the_middle() -> Result<()> {
+        let result = verify_that!(vec![1, 2, 3, 4], pointwise!(eq, vec![1, 3, 4]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "which has size 4 (expected 3); where element #1 is 2, which is unexpected"
+            )))
+        )
+    }
+
+    #[google_test]
+    fn pointwise_aligns_actual_value_with_an_element_missing_in_the_middle() -> Result<()> {
+        let result = verify_that!(vec![1, 3, 4], pointwise!(eq, vec![1, 2, 3, 4]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "which has size 3 (expected 4); where no element matches #1: expected is equal to 2"
+            )))
+        )
+    }
+
     #[google_test]
     fn pointwise_returns_mismatch_when_actual_value_does_not_match_on_first_item() -> Result<()> {
         let result = verify_that!(vec![1, 2, 3], pointwise!(eq, vec![2, 2, 3]));
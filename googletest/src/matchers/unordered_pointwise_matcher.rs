@@ -0,0 +1,297 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// There are no visible documentation elements in this module; the declarative
+// macro is documented at the top level.
+#![doc(hidden)]
+
+/// Generates a matcher which matches a container each of whose elements
+/// matches the given matcher name applied respectively to each element of the
+/// given container, in some order.
+///
+/// This is the same as [`pointwise!`][crate::pointwise] except that the
+/// matchers and the actual elements are not required to correspond by
+/// position. It succeeds so long as there exists *some* correspondence
+/// between them, i.e. some way of pairing up each actual element with a
+/// distinct matcher such that the actual element matches that matcher. This
+/// corresponds to `UnorderedPointwise` in C++ GoogleTest.
+///
+/// For example, the following matches a container of integers without regard
+/// to order:
+///
+/// ```
+/// let value = vec![1, 2, 3];
+/// verify_that!(value, unordered_pointwise!(eq, [3, 2, 1]))?; // Passes
+/// verify_that!(value, unordered_pointwise!(eq, [1, 2, 4]))?; // Fails
+/// ```
+///
+/// As with [`pointwise!`][crate::pointwise], the actual value must be a
+/// container implementing [`IntoIterator`] and
+/// [`HasSize`][crate::matchers::has_size::HasSize], and the second argument
+/// can be any value implementing `IntoIterator` whose items are of the same
+/// type as the actual container's elements.
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/std/iter/trait.IntoIterator.html
+#[macro_export]
+macro_rules! unordered_pointwise {
+    ($matcher:expr, $container:expr) => {{
+        #[cfg(google3)]
+        use $crate::internal::UnorderedPointwiseMatcher;
+        #[cfg(not(google3))]
+        use $crate::matchers::unordered_pointwise_matcher::internal::UnorderedPointwiseMatcher;
+        UnorderedPointwiseMatcher::new($container.into_iter().map(|t| $matcher(t)).collect())
+    }};
+}
+
+/// Module for use only by the procedural macros in this module.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub mod internal {
+    #[cfg(not(google3))]
+    use crate as googletest;
+    #[cfg(google3)]
+    use description::Description;
+    use googletest::matcher::{MatchExplanation, Matcher, MatcherResult};
+    #[cfg(not(google3))]
+    use googletest::matchers::description::Description;
+    use std::collections::HashSet;
+    use std::fmt::Debug;
+
+    /// This struct is meant to be used only through the `unordered_pointwise`
+    /// macro.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub struct UnorderedPointwiseMatcher<MatcherT> {
+        matchers: Vec<MatcherT>,
+    }
+
+    impl<MatcherT> UnorderedPointwiseMatcher<MatcherT> {
+        pub fn new(matchers: Vec<MatcherT>) -> Self {
+            Self { matchers }
+        }
+    }
+
+    /// Attempts to find an assignment of each matcher to a distinct,
+    /// compatible actual element using Kuhn's algorithm for maximum bipartite
+    /// matching.
+    ///
+    /// Returns, for each actual element (by index), the index of the matcher
+    /// assigned to it, or `None` if that element is left unmatched.
+    fn find_best_assignment<T: Debug, MatcherT: Matcher<T>>(
+        matchers: &[MatcherT],
+        actual: &[&T],
+    ) -> Vec<Option<usize>> {
+        let mut match_of_actual: Vec<Option<usize>> = vec![None; actual.len()];
+        for matcher_idx in 0..matchers.len() {
+            let mut visited = vec![false; actual.len()];
+            try_assign(
+                matchers,
+                actual,
+                matcher_idx,
+                &mut visited,
+                &mut match_of_actual,
+            );
+        }
+        match_of_actual
+    }
+
+    /// Tries to find an actual element for `matcher_idx`, reassigning
+    /// existing matches along an augmenting path if necessary. Returns
+    /// whether an assignment was found.
+    fn try_assign<T: Debug, MatcherT: Matcher<T>>(
+        matchers: &[MatcherT],
+        actual: &[&T],
+        matcher_idx: usize,
+        visited: &mut [bool],
+        match_of_actual: &mut [Option<usize>],
+    ) -> bool {
+        for (actual_idx, element) in actual.iter().enumerate() {
+            if visited[actual_idx]
+                || matches!(
+                    matchers[matcher_idx].matches(*element),
+                    MatcherResult::DoesNotMatch
+                )
+            {
+                continue;
+            }
+            visited[actual_idx] = true;
+            let can_reassign = match match_of_actual[actual_idx] {
+                None => true,
+                Some(other_matcher_idx) => try_assign(
+                    matchers,
+                    actual,
+                    other_matcher_idx,
+                    visited,
+                    match_of_actual,
+                ),
+            };
+            if can_reassign {
+                match_of_actual[actual_idx] = Some(matcher_idx);
+                return true;
+            }
+        }
+        false
+    }
+
+    impl<T: Debug, MatcherT: Matcher<T>, ContainerT: ?Sized + Debug> Matcher<ContainerT>
+        for UnorderedPointwiseMatcher<MatcherT>
+    where
+        for<'b> &'b ContainerT: IntoIterator<Item = &'b T>,
+    {
+        fn matches(&self, actual: &ContainerT) -> MatcherResult {
+            let actual = actual.into_iter().collect::<Vec<_>>();
+            if actual.len() != self.matchers.len() {
+                return MatcherResult::DoesNotMatch;
+            }
+            let assignment = find_best_assignment(&self.matchers, &actual);
+            if assignment.iter().all(Option::is_some) {
+                MatcherResult::Matches
+            } else {
+                MatcherResult::DoesNotMatch
+            }
+        }
+
+        fn explain_match(&self, actual: &ContainerT) -> MatchExplanation {
+            let actual = actual.into_iter().collect::<Vec<_>>();
+            if actual.len() != self.matchers.len() {
+                return MatchExplanation::create(format!(
+                    "which has size {} (expected {})",
+                    actual.len(),
+                    self.matchers.len()
+                ));
+            }
+            let assignment = find_best_assignment(&self.matchers, &actual);
+            if assignment.iter().all(Option::is_some) {
+                return MatchExplanation::create(
+                    "which has a perfect correspondence with the expected elements".to_string(),
+                );
+            }
+            let matched_matchers = assignment.iter().filter_map(|m| *m).collect::<HashSet<_>>();
+            let mut explanations = self
+                .matchers
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| !matched_matchers.contains(idx))
+                .map(|(idx, matcher)| {
+                    format!(
+                        "no element matches #{idx}: {}",
+                        matcher.describe(MatcherResult::Matches)
+                    )
+                })
+                .collect::<Vec<_>>();
+            explanations.extend(actual.iter().zip(assignment.iter()).enumerate().filter_map(
+                |(idx, (element, assigned))| {
+                    assigned
+                        .is_none()
+                        .then(|| format!("element #{idx} is {element:?}, which is unmatched"))
+                },
+            ));
+            let explanations = explanations.into_iter().collect::<Description>();
+            MatchExplanation::create(format!("where:\n{}", explanations.bullet_list().indent()))
+        }
+
+        fn describe(&self, matcher_result: MatcherResult) -> String {
+            format!(
+                "{} elements matching, in some order:\n{}",
+                matcher_result.pick("has", "doesn't have"),
+                self.matchers
+                    .iter()
+                    .map(|m| m.describe(MatcherResult::Matches))
+                    .collect::<Description>()
+                    .enumerate()
+                    .indent()
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(google3))]
+    use crate as googletest;
+    #[cfg(not(google3))]
+    use googletest::matchers;
+    use googletest::{google_test, verify_that, Result};
+    use indoc::indoc;
+    use matchers::{contains_substring, displays_as, eq, err, not};
+
+    #[google_test]
+    fn unordered_pointwise_matches_elements_in_same_order() -> Result<()> {
+        let value = vec![1, 2, 3];
+        verify_that!(value, unordered_pointwise!(eq, vec![1, 2, 3]))
+    }
+
+    #[google_test]
+    fn unordered_pointwise_matches_elements_in_different_order() -> Result<()> {
+        let value = vec![1, 2, 3];
+        verify_that!(value, unordered_pointwise!(eq, vec![3, 1, 2]))
+    }
+
+    #[google_test]
+    fn unordered_pointwise_does_not_match_when_an_element_is_missing() -> Result<()> {
+        let value = vec![1, 2, 3];
+        verify_that!(value, not(unordered_pointwise!(eq, vec![1, 2, 4])))
+    }
+
+    #[google_test]
+    fn unordered_pointwise_does_not_match_value_of_wrong_length() -> Result<()> {
+        let value = vec![1, 2];
+        verify_that!(value, not(unordered_pointwise!(eq, vec![1, 2, 3])))
+    }
+
+    #[google_test]
+    fn unordered_pointwise_matches_with_duplicated_expected_values() -> Result<()> {
+        let value = vec![1, 1, 2];
+        verify_that!(value, unordered_pointwise!(eq, vec![1, 2, 1]))
+    }
+
+    #[google_test]
+    fn unordered_pointwise_describes_expected_elements_in_any_order() -> Result<()> {
+        let result = verify_that!(vec![4, 5, 6], unordered_pointwise!(eq, vec![1, 2, 3]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: has elements matching, in some order:
+                  0. is equal to 1
+                  1. is equal to 2
+                  2. is equal to 3
+                "
+            ))))
+        )
+    }
+
+    #[google_test]
+    fn unordered_pointwise_explains_unmatched_matchers_and_elements() -> Result<()> {
+        let result = verify_that!(vec![1, 2, 4], unordered_pointwise!(eq, vec![1, 2, 3]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Actual: [
+                    1,
+                    2,
+                    4,
+                ], where:
+                  * no element matches #2: is equal to 3
+                  * element #2 is 4, which is unmatched
+                "
+            ))))
+        )
+    }
+}